@@ -3,12 +3,19 @@ use crate::find;
 use crate::input::Control;
 
 use anyhow::Result;
+use arboard::Clipboard;
 use regex::Regex;
 use std::cmp::min;
+use std::collections::HashSet;
 use std::time::Instant;
 
 struct RowsFilter {
+    // The windowed subset of matches for the page currently in view; this is
+    // what `do_get_rows`/`is_marked` index against.
     indices: Vec<u64>,
+    // The full match set across the whole file, fetched eagerly so
+    // `toggle_mark_all_matched` can mark every match, not just this page.
+    all_indices: Vec<u64>,
     total: usize,
 }
 
@@ -16,13 +23,333 @@ impl RowsFilter {
     fn new(finder: &find::Finder, rows_from: u64, num_rows: u64) -> RowsFilter {
         let total = finder.count();
         let indices = finder.get_subset_found(rows_from as usize, num_rows as usize);
-        RowsFilter { indices, total }
+        let all_indices = finder.get_subset_found(0, total);
+        RowsFilter {
+            indices,
+            all_indices,
+            total,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OverlapPlan {
+    /// Window didn't move; reuse the buffer as-is.
+    Unchanged,
+    /// Scrolled down: drop `delta` rows from the front, fetch `delta` new
+    /// rows starting at `fetch_from` (the old window's end) to append.
+    ExtendBack { delta: u64, fetch_from: u64 },
+    /// Scrolled up: fetch `delta` new rows starting at `fetch_from` (the new
+    /// window's start) to prepend, then drop `delta` rows from the back.
+    ExtendFront { delta: u64, fetch_from: u64 },
+    /// No overlap between old and new windows (or the window size changed);
+    /// a full read is required.
+    NoOverlap,
+}
+
+// NOTE for whoever wires fuzzy matching into `find::Finder` (that module
+// isn't part of this tree, so it can't be done here): `matching::parse_atoms`
+// / `matching::match_atoms` below already return per-candidate scores and
+// match positions. `RowsFilter::new` above is where a fuzzy-aware `Finder`
+// query would plug in, and `MatchResult::positions` is what row-highlighting
+// should consume once that wiring exists — the row-finding half of
+// JojiiOfficial/csvlens#chunk0-2 (fuzzy-driven row finding/highlighting, as
+// opposed to the column filter below) is UNIMPLEMENTED, not merely deferred;
+// flag it to whoever owns `crate::find` rather than treating the request as
+// done.
+/// Fuzzy, multi-atom matching engine, currently wired into `ColumnsFilter`
+/// only (see the NOTE above — it is not wired into row finding).
+///
+/// A query is split on whitespace into atoms that are ANDed together. Sigils
+/// pick the atom kind: `^` prefix, `$` suffix, `^...$` exact, leading `'`
+/// plain substring, leading `!` negates the atom, and no sigil means a fuzzy
+/// subsequence match. Matching is case-insensitive unless the atom itself
+/// contains an uppercase letter (smart case).
+pub(crate) mod matching {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum AtomKind {
+        Fuzzy,
+        Prefix,
+        Suffix,
+        Exact,
+        Substring,
+    }
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct Atom {
+        kind: AtomKind,
+        text: String,
+        negate: bool,
+        case_sensitive: bool,
+    }
+
+    impl Atom {
+        fn parse(raw: &str) -> Option<Atom> {
+            let mut rest = raw;
+            let negate = if let Some(s) = rest.strip_prefix('!') {
+                rest = s;
+                true
+            } else {
+                false
+            };
+            if rest.is_empty() {
+                return None;
+            }
+            let (kind, text) = if let Some(s) = rest.strip_prefix('\'') {
+                (AtomKind::Substring, s)
+            } else if let Some(s) = rest.strip_prefix('^').and_then(|s| s.strip_suffix('$')) {
+                (AtomKind::Exact, s)
+            } else if let Some(s) = rest.strip_prefix('^') {
+                (AtomKind::Prefix, s)
+            } else if let Some(s) = rest.strip_suffix('$') {
+                (AtomKind::Suffix, s)
+            } else {
+                (AtomKind::Fuzzy, rest)
+            };
+            if text.is_empty() {
+                return None;
+            }
+            let case_sensitive = text.chars().any(|c| c.is_uppercase());
+            Some(Atom {
+                kind,
+                text: text.to_string(),
+                negate,
+                case_sensitive,
+            })
+        }
+
+        /// Matches `candidate`, returning a score (higher is better) on success.
+        pub(crate) fn matches(&self, candidate: &str) -> Option<MatchResult> {
+            let (needle, haystack) = if self.case_sensitive {
+                (self.text.clone(), candidate.to_string())
+            } else {
+                (self.text.to_lowercase(), candidate.to_lowercase())
+            };
+            let result = match self.kind {
+                AtomKind::Substring => haystack.find(&needle).map(|byte_start| {
+                    let start = haystack[..byte_start].chars().count();
+                    MatchResult::plain(start, needle.chars().count())
+                }),
+                AtomKind::Prefix => haystack
+                    .starts_with(&needle)
+                    .then(|| MatchResult::plain(0, needle.chars().count())),
+                AtomKind::Suffix => haystack.ends_with(&needle).then(|| {
+                    let start = haystack.chars().count() - needle.chars().count();
+                    MatchResult::plain(start, needle.chars().count())
+                }),
+                AtomKind::Exact => {
+                    (haystack == needle).then(|| MatchResult::plain(0, needle.chars().count()))
+                }
+                AtomKind::Fuzzy => fuzzy_match(&needle, &haystack),
+            };
+            if self.negate {
+                return if result.is_none() {
+                    Some(MatchResult::default())
+                } else {
+                    None
+                };
+            }
+            result
+        }
+    }
+
+    /// Outcome of a single atom match: a score (higher is better) and, for
+    /// highlighting, the matched character positions within the candidate.
+    #[derive(Debug, Clone, Default)]
+    pub(crate) struct MatchResult {
+        pub(crate) score: i64,
+        pub(crate) positions: Vec<usize>,
+    }
+
+    impl MatchResult {
+        fn plain(start: usize, len: usize) -> MatchResult {
+            MatchResult {
+                score: 0,
+                positions: (start..start + len).collect(),
+            }
+        }
+    }
+
+    /// Subsequence fuzzy match of `needle` within `haystack`. Rewards
+    /// consecutive matches and matches at word boundaries, penalizes gaps.
+    fn fuzzy_match(needle: &str, haystack: &str) -> Option<MatchResult> {
+        let haystack: Vec<char> = haystack.chars().collect();
+        let mut hi = 0;
+        let mut score: i64 = 0;
+        let mut positions = vec![];
+        let mut prev_matched_at: Option<usize> = None;
+        for nc in needle.chars() {
+            let mut found = None;
+            while hi < haystack.len() {
+                if haystack[hi] == nc {
+                    found = Some(hi);
+                    break;
+                }
+                hi += 1;
+            }
+            let pos = found?;
+            let at_boundary = pos == 0 || !haystack[pos - 1].is_alphanumeric();
+            let consecutive = prev_matched_at.map(|p| p + 1 == pos).unwrap_or(true);
+            score += 10;
+            if at_boundary {
+                score += 8;
+            }
+            if consecutive {
+                score += 5;
+            } else if let Some(p) = prev_matched_at {
+                score -= (pos - p) as i64;
+            }
+            positions.push(pos);
+            prev_matched_at = Some(pos);
+            hi = pos + 1;
+        }
+        Some(MatchResult { score, positions })
+    }
+
+    /// Parses a query into AND-combined atoms (space-separated).
+    pub(crate) fn parse_atoms(query: &str) -> Vec<Atom> {
+        query.split_whitespace().filter_map(Atom::parse).collect()
+    }
+
+    /// Matches `candidate` against all atoms (AND), returning the combined
+    /// result (summed score, concatenated match positions) on success.
+    pub(crate) fn match_atoms(atoms: &[Atom], candidate: &str) -> Option<MatchResult> {
+        let mut combined = MatchResult::default();
+        for atom in atoms {
+            let result = atom.matches(candidate)?;
+            combined.score += result.score;
+            combined.positions.extend(result.positions);
+        }
+        Some(combined)
+    }
+}
+
+/// One item of a column selection spec, e.g. `3`, `2-5`, `-4`, `name`, `"quoted name"`
+/// or `/regex/`.
+#[derive(Debug, Clone)]
+enum Selector {
+    /// 1-based column index.
+    Index(usize),
+    /// Inclusive 1-based index range. Either end may be open (`-4`, `6-`).
+    Range(Option<usize>, Option<usize>),
+    /// A bare or quoted header name.
+    Name(String),
+    /// A `/regex/` pattern matched against header names.
+    Pattern(Regex),
+}
+
+impl Selector {
+    /// Splits a selection spec body on top-level commas, treating `"..."` and
+    /// `/.../` spans as atomic so a comma inside a quoted name or a regex
+    /// pattern doesn't get mistaken for an item separator.
+    fn split_items(body: &str) -> Vec<String> {
+        let mut items = vec![];
+        let mut current = String::new();
+        let mut in_quotes = false;
+        let mut in_regex = false;
+        for c in body.chars() {
+            match c {
+                '"' if !in_regex => {
+                    in_quotes = !in_quotes;
+                    current.push(c);
+                }
+                '/' if !in_quotes => {
+                    in_regex = !in_regex;
+                    current.push(c);
+                }
+                ',' if !in_quotes && !in_regex => {
+                    items.push(std::mem::take(&mut current));
+                }
+                _ => current.push(c),
+            }
+        }
+        items.push(current);
+        items
+    }
+
+    /// Parses a single item of a selection spec (as produced by `split_items`).
+    fn parse(item: &str) -> Selector {
+        let item = item.trim();
+        if let Some(inner) = item
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'))
+        {
+            if let Ok(re) = Regex::new(inner) {
+                return Selector::Pattern(re);
+            }
+        }
+        if let Some(range) = Self::parse_range(item) {
+            return range;
+        }
+        if let Ok(index) = item.parse::<usize>() {
+            return Selector::Index(index);
+        }
+        if let Some(name) = item
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+        {
+            return Selector::Name(name.to_string());
+        }
+        Selector::Name(item.to_string())
+    }
+
+    /// Tries to parse `item` as an (optionally open-ended) index range like `2-5`, `-4` or `6-`.
+    fn parse_range(item: &str) -> Option<Selector> {
+        let (start, end) = item.split_once('-')?;
+        let start = start.trim();
+        let end = end.trim();
+        if start.is_empty() && end.is_empty() {
+            return None;
+        }
+        let start = if start.is_empty() {
+            None
+        } else {
+            Some(start.parse::<usize>().ok()?)
+        };
+        let end = if end.is_empty() {
+            None
+        } else {
+            Some(end.parse::<usize>().ok()?)
+        };
+        Some(Selector::Range(start, end))
+    }
+
+    /// Resolves this selector against `headers`, in header order.
+    fn resolve(&self, headers: &[String]) -> Vec<usize> {
+        match self {
+            Selector::Index(i) => {
+                if *i >= 1 && *i <= headers.len() {
+                    vec![*i - 1]
+                } else {
+                    vec![]
+                }
+            }
+            Selector::Range(start, end) => {
+                let start = start.unwrap_or(1).max(1);
+                let end = end.unwrap_or(headers.len()).min(headers.len());
+                (start..=end)
+                    .filter(|_| start <= end)
+                    .map(|i| i - 1)
+                    .collect()
+            }
+            Selector::Name(name) => headers
+                .iter()
+                .position(|header| header == name)
+                .into_iter()
+                .collect(),
+            Selector::Pattern(pattern) => headers
+                .iter()
+                .enumerate()
+                .filter(|(_, header)| pattern.is_match(header))
+                .map(|(i, _)| i)
+                .collect(),
+        }
     }
 }
 
 #[derive(Debug)]
 pub struct ColumnsFilter {
-    pattern: Regex,
+    spec: String,
     indices: Vec<usize>,
     filtered_headers: Vec<String>,
     num_columns_before_filter: usize,
@@ -30,25 +357,67 @@ pub struct ColumnsFilter {
 }
 
 impl ColumnsFilter {
-    fn new(pattern: Regex, headers: &[String]) -> Self {
-        let mut indices = vec![];
-        let mut filtered_headers: Vec<String> = vec![];
-        for (i, header) in headers.iter().enumerate() {
-            if pattern.is_match(header) {
-                indices.push(i);
-                filtered_headers.push(header.clone());
-            }
-        }
-        let disabled_because_no_match;
-        if indices.is_empty() {
-            indices = (0..headers.len()).collect();
-            filtered_headers = headers.into();
-            disabled_because_no_match = true;
-        } else {
-            disabled_because_no_match = false;
+    /// Builds a filter from a column selection spec: comma-separated selectors
+    /// (indices, ranges, names or `/regex/` patterns), optionally prefixed with
+    /// `!` to invert the resulting set. Resolved columns keep the order the
+    /// selectors appear in, so `3,1,2` reorders and `1,1` duplicates.
+    fn new(spec: &str, headers: &[String]) -> Self {
+        let trimmed = spec.trim();
+        let (invert, body) = match trimmed.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+
+        let mut indices: Vec<usize> = vec![];
+        for item in Selector::split_items(body) {
+            if item.trim().is_empty() {
+                continue;
+            }
+            indices.extend(Selector::parse(&item).resolve(headers));
+        }
+
+        if invert {
+            let selected: HashSet<usize> = indices.iter().copied().collect();
+            indices = (0..headers.len())
+                .filter(|i| !selected.contains(i))
+                .collect();
         }
+
+        Self::from_indices(trimmed.to_string(), indices, headers)
+    }
+
+    /// Builds a filter using the fuzzy multi-atom matching engine instead of
+    /// the selector DSL, ordering surviving columns best-match-first.
+    fn new_fuzzy(query: &str, headers: &[String]) -> Self {
+        let atoms = matching::parse_atoms(query);
+        let mut scored: Vec<(usize, i64)> = headers
+            .iter()
+            .enumerate()
+            .filter_map(|(i, header)| {
+                matching::match_atoms(&atoms, header).map(|result| (i, result.score))
+            })
+            .collect();
+        scored.sort_by_key(|b| std::cmp::Reverse(b.1));
+        let indices: Vec<usize> = scored.into_iter().map(|(i, _)| i).collect();
+
+        Self::from_indices(query.trim().to_string(), indices, headers)
+    }
+
+    /// Shared tail end of `new`/`new_fuzzy`: derives `filtered_headers` from
+    /// `indices`, falling back to showing every column when `indices` is
+    /// empty (an empty selection is treated as "no filter" rather than "show
+    /// nothing").
+    fn from_indices(spec: String, indices: Vec<usize>, headers: &[String]) -> Self {
+        let disabled_because_no_match = indices.is_empty();
+        let (indices, filtered_headers) = if disabled_because_no_match {
+            ((0..headers.len()).collect(), headers.into())
+        } else {
+            let filtered_headers = indices.iter().map(|&i| headers[i].clone()).collect();
+            (indices, filtered_headers)
+        };
+
         Self {
-            pattern,
+            spec,
             indices,
             filtered_headers,
             num_columns_before_filter: headers.len(),
@@ -64,8 +433,8 @@ impl ColumnsFilter {
         &self.indices
     }
 
-    pub fn pattern(&self) -> Regex {
-        self.pattern.to_owned()
+    pub fn spec(&self) -> &str {
+        &self.spec
     }
 
     pub fn num_filtered(&self) -> usize {
@@ -149,13 +518,61 @@ pub enum SelectionType {
     Row,
     Column,
     Cell,
+    Block,
     None,
 }
 
+/// A rectangular selection spanning multiple rows and columns, like a
+/// terminal drag-selection: an `anchor` that stays put and a `cursor` that
+/// moves, both as view-relative `(row, column)` indices.
+#[derive(Clone)]
+pub struct Block {
+    anchor: (u64, u64),
+    cursor: (u64, u64),
+}
+
+impl Block {
+    fn new(row: u64, column: u64) -> Self {
+        Block {
+            anchor: (row, column),
+            cursor: (row, column),
+        }
+    }
+
+    /// Whether the (view-relative) `(row, column)` cell falls within this
+    /// block, normalizing anchor/cursor regardless of drag direction. When
+    /// anchor and cursor are on the same row, only that row's column span is
+    /// included; otherwise every column in the rectangle between the corners is.
+    pub fn contains(&self, row: u64, column: u64) -> bool {
+        let (row_start, row_end) = (
+            self.anchor.0.min(self.cursor.0),
+            self.anchor.0.max(self.cursor.0),
+        );
+        let (col_start, col_end) = (
+            self.anchor.1.min(self.cursor.1),
+            self.anchor.1.max(self.cursor.1),
+        );
+        if row_start == row_end {
+            row == row_start && column >= col_start && column <= col_end
+        } else {
+            row >= row_start && row <= row_end && column >= col_start && column <= col_end
+        }
+    }
+
+    fn set_bound(&mut self, row_bound: u64, column_bound: u64) {
+        let clamp = |v: u64, bound: u64| min(v, bound.saturating_sub(1));
+        self.anchor.0 = clamp(self.anchor.0, row_bound);
+        self.cursor.0 = clamp(self.cursor.0, row_bound);
+        self.anchor.1 = clamp(self.anchor.1, column_bound);
+        self.cursor.1 = clamp(self.cursor.1, column_bound);
+    }
+}
+
 #[derive(Clone)]
 pub struct Selection {
     pub row: SelectionDimension,
     pub column: SelectionDimension,
+    pub block: Option<Block>,
 }
 
 impl Selection {
@@ -169,11 +586,14 @@ impl Selection {
                 index: None,
                 bound: 0,
             },
+            block: None,
         }
     }
 
     pub fn selection_type(&self) -> SelectionType {
-        if self.row.index.is_some() && self.column.index.is_some() {
+        if self.block.is_some() {
+            SelectionType::Block
+        } else if self.row.index.is_some() && self.column.index.is_some() {
             SelectionType::Cell
         } else if self.row.index.is_some() {
             SelectionType::Row
@@ -194,6 +614,19 @@ pub struct RowsView {
     columns_filter: Option<ColumnsFilter>,
     pub selection: Selection,
     elapsed: Option<u128>,
+    // Absolute row the unfiltered `rows` buffer currently starts at, used to
+    // reuse overlapping rows on a small scroll instead of re-reading the
+    // whole window. `None` when the buffer doesn't reflect a plain
+    // `[rows_from, rows_from + num_rows)` window (e.g. a filter is active).
+    buffered_from: Option<u64>,
+    // Absolute record offsets of marked rows, keyed independently of the
+    // current scroll position or filter so marks survive both.
+    marks: HashSet<u64>,
+    // Whether the "extend selection" modifier is currently held, set by
+    // external input handling via `set_extend_selection`. While true,
+    // `sync_block_selection` grows the active block's cursor corner to the
+    // new position instead of re-anchoring it.
+    extend_selection: bool,
 }
 
 impl RowsView {
@@ -209,6 +642,9 @@ impl RowsView {
             columns_filter: None,
             selection: Selection::default(num_rows),
             elapsed: None,
+            buffered_from: Some(rows_from),
+            marks: HashSet::new(),
+            extend_selection: false,
         };
         Ok(view)
     }
@@ -287,8 +723,13 @@ impl RowsView {
         self.columns_filter.as_ref()
     }
 
-    pub fn set_columns_filter(&mut self, target: Regex) -> Result<()> {
-        self.columns_filter = Some(ColumnsFilter::new(target, &self.reader.headers));
+    pub fn set_columns_filter(&mut self, target: &str, fuzzy: bool) -> Result<()> {
+        let filter = if fuzzy {
+            ColumnsFilter::new_fuzzy(target, &self.reader.headers)
+        } else {
+            ColumnsFilter::new(target, &self.reader.headers)
+        };
+        self.columns_filter = Some(filter);
         self.do_get_rows()
     }
 
@@ -322,6 +763,182 @@ impl RowsView {
             .map(|x| x.saturating_add(self.rows_from))
     }
 
+    /// Maps a view-relative row index (an index into `rows()`) to its
+    /// absolute record offset. When a filter is active, `rows_from` is an
+    /// index into the match list rather than an absolute row number, so this
+    /// goes through `RowsFilter::indices` instead of just adding `rows_from`.
+    /// Shared by `toggle_mark` and `is_marked` so the two can't drift apart
+    /// on how they compute the same offset.
+    fn absolute_offset(&self, view_row_index: usize) -> Option<u64> {
+        if let Some(filter) = &self.filter {
+            filter.indices.get(view_row_index).copied()
+        } else {
+            Some(self.rows_from.saturating_add(view_row_index as u64))
+        }
+    }
+
+    /// Toggles the mark on the currently selected row. Marks key on the
+    /// absolute record offset, so they survive scrolling and filtering.
+    pub fn toggle_mark(&mut self) {
+        let view_row_index = match self.selection.row.index() {
+            Some(i) => i as usize,
+            None => return,
+        };
+        if let Some(offset) = self.absolute_offset(view_row_index) {
+            if !self.marks.remove(&offset) {
+                self.marks.insert(offset);
+            }
+        }
+    }
+
+    /// Toggles the mark on every row matched by the active filter across the
+    /// whole file (or, with no filter active, every row currently in view).
+    /// If all of them are already marked, unmarks them instead. Returns
+    /// `(count, total_matches)`, where `count` is the number of rows the
+    /// toggle covered, whether it marked or unmarked them.
+    pub fn toggle_mark_all_matched(&mut self) -> (usize, usize) {
+        let offsets: Vec<u64> = if let Some(filter) = &self.filter {
+            filter.all_indices.clone()
+        } else {
+            (0..self.rows.len() as u64)
+                .map(|i| self.rows_from.saturating_add(i))
+                .collect()
+        };
+        let total = self.filter.as_ref().map_or(offsets.len(), |f| f.total);
+        if offsets.iter().all(|offset| self.marks.contains(offset)) {
+            for offset in &offsets {
+                self.marks.remove(offset);
+            }
+        } else {
+            self.marks.extend(offsets.iter().copied());
+        }
+        (offsets.len(), total)
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marks.clear();
+    }
+
+    /// Whether the row at `view_row_index` (an index into `rows()`) is
+    /// marked, mapping the visible index back through `rows_from`, or through
+    /// `RowsFilter::indices` when a filter is active.
+    pub fn is_marked(&self, view_row_index: usize) -> bool {
+        match self.absolute_offset(view_row_index) {
+            Some(offset) => self.marks.contains(&offset),
+            None => false,
+        }
+    }
+
+    /// Exports the marked rows, or, if none are marked, the current
+    /// `Selection` (cell, row, column or block), as delimiter-separated text
+    /// and pushes it to the system clipboard. Returns `false` if there was
+    /// nothing to export.
+    pub fn yank(&mut self, delimiter: char) -> Result<bool> {
+        let cells = if self.marks.is_empty() {
+            self.export_selection_cells()
+        } else {
+            self.export_marked_cells()?
+        };
+        if cells.is_empty() {
+            return Ok(false);
+        }
+        let text = Self::cells_to_text(&cells, delimiter);
+        let mut clipboard = Clipboard::new()?;
+        clipboard.set_text(text)?;
+        Ok(true)
+    }
+
+    fn export_marked_cells(&mut self) -> Result<Vec<Vec<String>>> {
+        let mut offsets: Vec<u64> = self.marks.iter().copied().collect();
+        offsets.sort_unstable();
+        let mut rows = self.reader.get_rows_for_indices(&offsets)?;
+        if let Some(columns_filter) = &self.columns_filter {
+            rows = Self::subset_columns(&rows, columns_filter.indices());
+        }
+        Ok(rows.into_iter().map(|row| row.fields).collect())
+    }
+
+    fn export_selection_cells(&self) -> Vec<Vec<String>> {
+        match self.selection.selection_type() {
+            SelectionType::Block => {
+                let block = match &self.selection.block {
+                    Some(block) => block,
+                    None => return vec![],
+                };
+                self.rows
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(row_index, row)| {
+                        let fields: Vec<String> = row
+                            .fields
+                            .iter()
+                            .enumerate()
+                            .filter(|(col_index, _)| {
+                                block.contains(row_index as u64, *col_index as u64)
+                            })
+                            .map(|(_, field)| field.clone())
+                            .collect();
+                        if fields.is_empty() {
+                            None
+                        } else {
+                            Some(fields)
+                        }
+                    })
+                    .collect()
+            }
+            SelectionType::Cell => {
+                match (self.selection.row.index(), self.selection.column.index()) {
+                    (Some(row_index), Some(col_index)) => self
+                        .rows
+                        .get(row_index as usize)
+                        .and_then(|row| row.fields.get(col_index as usize))
+                        .map(|field| vec![vec![field.clone()]])
+                        .unwrap_or_default(),
+                    _ => vec![],
+                }
+            }
+            SelectionType::Row => match self.selection.row.index() {
+                Some(row_index) => self
+                    .rows
+                    .get(row_index as usize)
+                    .map(|row| vec![row.fields.clone()])
+                    .unwrap_or_default(),
+                None => vec![],
+            },
+            SelectionType::Column => match self.selection.column.index() {
+                Some(col_index) => self
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.fields.get(col_index as usize))
+                    .map(|field| vec![field.clone()])
+                    .collect(),
+                None => vec![],
+            },
+            SelectionType::None => vec![],
+        }
+    }
+
+    fn cells_to_text(cells: &[Vec<String>], delimiter: char) -> String {
+        cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|field| Self::escape_field(field, delimiter))
+                    .collect::<Vec<_>>()
+                    .join(&delimiter.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn escape_field(field: &str, delimiter: char) -> String {
+        if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
     pub fn elapsed(&self) -> Option<u128> {
         self.elapsed
     }
@@ -342,6 +959,50 @@ impl RowsView {
         false
     }
 
+    /// Starts a block (rectangular) selection anchored at the currently
+    /// selected cell, or clears one if already active.
+    pub fn toggle_block_selection(&mut self) {
+        if self.selection.block.is_some() {
+            self.selection.block = None;
+        } else {
+            let row = self.selection.row.index().unwrap_or(0);
+            let column = self.selection.column.index().unwrap_or(0);
+            self.selection.block = Some(Block::new(row, column));
+        }
+    }
+
+    /// Sets whether the "extend selection" modifier is currently held, e.g.
+    /// while a drag/shift key is down in the input layer. Call this from
+    /// wherever that modifier's state changes, before the matching
+    /// `handle_control` calls or `selection.column` mutations arrive.
+    pub fn set_extend_selection(&mut self, extend: bool) {
+        self.extend_selection = extend;
+    }
+
+    /// Keeps an active block selection in sync with the current `(row,
+    /// column)` selection. While the extend-selection modifier is held, this
+    /// grows the block by moving its cursor corner to the new position;
+    /// otherwise it re-anchors the block at the new position, so that plain
+    /// navigation without the modifier moves a single-cell selection rather
+    /// than resizing the old block. `handle_control` calls this after every
+    /// vertical navigation control; any other code that moves the selection
+    /// directly (e.g. mutating `selection.column` for horizontal movement)
+    /// must call this afterwards too to keep the block's column in sync.
+    pub fn sync_block_selection(&mut self) {
+        if self.selection.block.is_none() {
+            return;
+        }
+        let row = self.selection.row.index().unwrap_or(0);
+        let column = self.selection.column.index().unwrap_or(0);
+        if self.extend_selection {
+            if let Some(block) = &mut self.selection.block {
+                block.cursor = (row, column);
+            }
+        } else {
+            self.selection.block = Some(Block::new(row, column));
+        }
+    }
+
     pub fn handle_control(&mut self, control: &Control) -> Result<()> {
         match control {
             Control::ScrollDown => {
@@ -395,6 +1056,7 @@ impl RowsView {
             }
             _ => {}
         }
+        self.sync_block_selection();
         Ok(())
     }
 
@@ -439,15 +1101,88 @@ impl RowsView {
         out
     }
 
+    /// Tries to satisfy the current `[rows_from, rows_from + num_rows)` window
+    /// by reusing rows already buffered in `self.rows`, fetching only the rows
+    /// newly exposed at the top or bottom. Returns `None` when a full read is
+    /// needed: no `buffered_from` (filter active or not yet established), no
+    /// overlap with the previous window, or the buffer size doesn't match
+    /// `num_rows` (e.g. right after `set_num_rows`).
+    fn reuse_overlap(&mut self) -> Result<Option<(Vec<Row>, u128)>> {
+        let old_from = match self.buffered_from {
+            Some(n) => n,
+            None => return Ok(None),
+        };
+        let old_len = self.rows.len() as u64;
+        match Self::plan_overlap(old_from, old_len, self.rows_from, self.num_rows) {
+            OverlapPlan::NoOverlap => Ok(None),
+            OverlapPlan::Unchanged => Ok(Some((self.rows.clone(), 0))),
+            OverlapPlan::ExtendBack { delta, fetch_from } => {
+                let mut rows = std::mem::take(&mut self.rows);
+                let start = Instant::now();
+                rows.drain(0..delta as usize);
+                let mut tail = self.reader.get_rows(fetch_from, delta)?;
+                rows.append(&mut tail);
+                let elapsed = start.elapsed().as_micros();
+                Ok(Some((rows, elapsed)))
+            }
+            OverlapPlan::ExtendFront { delta, fetch_from } => {
+                let mut rows = std::mem::take(&mut self.rows);
+                let start = Instant::now();
+                let mut head = self.reader.get_rows(fetch_from, delta)?;
+                head.append(&mut rows);
+                head.truncate(self.num_rows as usize);
+                let elapsed = start.elapsed().as_micros();
+                Ok(Some((head, elapsed)))
+            }
+        }
+    }
+
+    /// Pure window-overlap arithmetic for `reuse_overlap`, split out so the
+    /// off-by-one-prone math can be unit tested without a `CsvLensReader`.
+    /// `old_from`/`old_len` describe the currently buffered window, `new_from`
+    /// the requested `rows_from`, and `num_rows` the requested window size.
+    fn plan_overlap(old_from: u64, old_len: u64, new_from: u64, num_rows: u64) -> OverlapPlan {
+        if old_len != num_rows {
+            return OverlapPlan::NoOverlap;
+        }
+        if new_from == old_from {
+            return OverlapPlan::Unchanged;
+        }
+        let old_end = old_from + old_len;
+        if new_from >= old_end || new_from + num_rows <= old_from {
+            return OverlapPlan::NoOverlap;
+        }
+        if new_from > old_from {
+            OverlapPlan::ExtendBack {
+                delta: new_from - old_from,
+                fetch_from: old_end,
+            }
+        } else {
+            OverlapPlan::ExtendFront {
+                delta: old_from - new_from,
+                fetch_from: new_from,
+            }
+        }
+    }
+
     fn do_get_rows(&mut self) -> Result<()> {
-        let start = Instant::now();
-        let mut rows = if let Some(filter) = &self.filter {
+        let (mut rows, elapsed) = if let Some(filter) = &self.filter {
             let indices = &filter.indices;
-            self.reader.get_rows_for_indices(indices)?
+            self.buffered_from = None;
+            let start = Instant::now();
+            let rows = self.reader.get_rows_for_indices(indices)?;
+            (rows, start.elapsed().as_micros())
+        } else if let Some((rows, elapsed)) = self.reuse_overlap()? {
+            // A column filter projects `self.rows` below, so the buffer then no
+            // longer holds whole records and can't be reused on the next read.
+            self.buffered_from = self.columns_filter.is_none().then_some(self.rows_from);
+            (rows, elapsed)
         } else {
-            self.reader.get_rows(self.rows_from, self.num_rows)?
+            let start = Instant::now();
+            let rows = self.reader.get_rows(self.rows_from, self.num_rows)?;
+            self.buffered_from = self.columns_filter.is_none().then_some(self.rows_from);
+            (rows, start.elapsed().as_micros())
         };
-        let elapsed = start.elapsed().as_micros();
         if let Some(columns_filter) = &self.columns_filter {
             rows = Self::subset_columns(&rows, columns_filter.indices());
         }
@@ -458,6 +1193,171 @@ impl RowsView {
         if let Some(row) = self.rows().first() {
             self.selection.column.set_bound(row.fields.len() as u64);
         }
+        if let Some(block) = &mut self.selection.block {
+            let num_columns = self.rows.first().map_or(0, |row| row.fields.len() as u64);
+            block.set_bound(self.rows.len() as u64, num_columns);
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_overlap_unchanged_when_window_did_not_move() {
+        let plan = RowsView::plan_overlap(10, 20, 10, 20);
+        assert_eq!(plan, OverlapPlan::Unchanged);
+    }
+
+    #[test]
+    fn plan_overlap_extend_back_on_scroll_down_by_one() {
+        // Old window [10, 30), new window [11, 31): scrolled down by 1, 19 rows
+        // of overlap, 1 new row to fetch at the old window's end.
+        let plan = RowsView::plan_overlap(10, 20, 11, 20);
+        assert_eq!(
+            plan,
+            OverlapPlan::ExtendBack {
+                delta: 1,
+                fetch_from: 30,
+            }
+        );
+    }
+
+    #[test]
+    fn plan_overlap_extend_back_at_max_delta_before_no_overlap() {
+        // delta == num_rows - 1 is the largest scroll-down that still overlaps.
+        let plan = RowsView::plan_overlap(0, 20, 19, 20);
+        assert_eq!(
+            plan,
+            OverlapPlan::ExtendBack {
+                delta: 19,
+                fetch_from: 20,
+            }
+        );
+    }
+
+    #[test]
+    fn plan_overlap_extend_front_on_scroll_up_by_one() {
+        let plan = RowsView::plan_overlap(11, 20, 10, 20);
+        assert_eq!(
+            plan,
+            OverlapPlan::ExtendFront {
+                delta: 1,
+                fetch_from: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn plan_overlap_extend_front_at_max_delta_before_no_overlap() {
+        let plan = RowsView::plan_overlap(19, 20, 0, 20);
+        assert_eq!(
+            plan,
+            OverlapPlan::ExtendFront {
+                delta: 19,
+                fetch_from: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn plan_overlap_no_overlap_when_windows_are_exactly_adjacent() {
+        // Old window [0, 20), new window [20, 40): touching but not overlapping.
+        let plan = RowsView::plan_overlap(0, 20, 20, 20);
+        assert_eq!(plan, OverlapPlan::NoOverlap);
+    }
+
+    #[test]
+    fn plan_overlap_no_overlap_when_windows_are_far_apart() {
+        let plan = RowsView::plan_overlap(0, 20, 1000, 20);
+        assert_eq!(plan, OverlapPlan::NoOverlap);
+    }
+
+    #[test]
+    fn plan_overlap_no_overlap_when_window_size_changed() {
+        // Same start, but a resized window can't reuse the old buffer as-is.
+        let plan = RowsView::plan_overlap(10, 20, 10, 25);
+        assert_eq!(plan, OverlapPlan::NoOverlap);
+    }
+
+    fn headers(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn selector_split_items_ignores_commas_inside_quotes_and_regex() {
+        let items = Selector::split_items(r#"1,"a, b",/x,y/,2"#);
+        assert_eq!(items, vec!["1", r#""a, b""#, "/x,y/", "2"]);
+    }
+
+    #[test]
+    fn selector_resolves_index_range_name_and_pattern() {
+        let h = headers(&["id", "name", "age", "email"]);
+        assert_eq!(Selector::parse("1").resolve(&h), vec![0]);
+        assert_eq!(Selector::parse("2-3").resolve(&h), vec![1, 2]);
+        assert_eq!(Selector::parse("-2").resolve(&h), vec![0, 1]);
+        assert_eq!(Selector::parse("3-").resolve(&h), vec![2, 3]);
+        assert_eq!(Selector::parse("name").resolve(&h), vec![1]);
+        assert_eq!(Selector::parse("/^e/").resolve(&h), vec![3]);
+        assert!(Selector::parse("nope").resolve(&h).is_empty());
+    }
+
+    #[test]
+    fn columns_filter_empty_selection_falls_back_to_showing_everything() {
+        let h = headers(&["id", "name"]);
+        let filter = ColumnsFilter::new("nonexistent", &h);
+        assert!(filter.disabled_because_no_match);
+        assert_eq!(filter.indices().as_slice(), &[0, 1]);
+    }
+
+    #[test]
+    fn block_contains_single_row_span() {
+        let mut block = Block::new(2, 4);
+        block.cursor = (2, 1);
+        assert!(block.contains(2, 1));
+        assert!(block.contains(2, 4));
+        assert!(!block.contains(2, 5));
+        assert!(!block.contains(3, 2));
+    }
+
+    #[test]
+    fn block_contains_multi_row_rectangle_regardless_of_drag_direction() {
+        let mut block = Block::new(5, 5);
+        block.cursor = (1, 1);
+        for row in 1..=5 {
+            for col in 1..=5 {
+                assert!(block.contains(row, col));
+            }
+        }
+        assert!(!block.contains(0, 1));
+        assert!(!block.contains(1, 0));
+    }
+
+    #[test]
+    fn fuzzy_match_atoms_ands_prefix_and_negation() {
+        let atoms = matching::parse_atoms("^foo !bar");
+        assert!(matching::match_atoms(&atoms, "foobaz").is_some());
+        assert!(matching::match_atoms(&atoms, "foobar").is_none());
+        assert!(matching::match_atoms(&atoms, "baz").is_none());
+    }
+
+    #[test]
+    fn fuzzy_match_subsequence_scores_consecutive_matches_higher() {
+        let atoms = matching::parse_atoms("abc");
+        let consecutive = matching::match_atoms(&atoms, "abcxyz").unwrap();
+        let scattered = matching::match_atoms(&atoms, "axbxcx").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn fuzzy_match_respects_smart_case() {
+        let atoms = matching::parse_atoms("Foo");
+        assert!(matching::match_atoms(&atoms, "foo bar").is_none());
+        assert!(matching::match_atoms(&atoms, "Foo bar").is_some());
+
+        let atoms = matching::parse_atoms("foo");
+        assert!(matching::match_atoms(&atoms, "FOO").is_some());
+    }
+}